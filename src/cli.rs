@@ -1,4 +1,4 @@
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 
 /// CLI arguments parser using `clap`
 #[derive(Parser, Debug)]
@@ -15,23 +15,66 @@ pub enum Commands {
     Switch {
         /// Alias of user to switch to
         user_alias: String,
+        /// Git config scope to write the identity to
+        #[arg(long, value_enum, default_value_t = ConfigScope::Local)]
+        scope: ConfigScope,
     },
     /// Adds a new user profile
     Add {
-        /// Git username
-        git_username: String,
-        /// Git email
-        git_email: String,
-        /// Unique alias for the user
-        user_alias: String,
+        /// Git username (omit when using --from-github)
+        git_username: Option<String>,
+        /// Git email (omit when using --from-github)
+        git_email: Option<String>,
+        /// Unique alias for the user (omit when using --from-github to be prompted for it)
+        user_alias: Option<String>,
+        /// Import the username and email from a GitHub account's public profile
+        #[arg(long, value_name = "LOGIN")]
+        from_github: Option<String>,
     },
     /// Deletes a user profile
     Delete {
         /// Alias of user to delete
         user_alias: String,
     },
+    /// Edits an existing user profile
+    Edit {
+        /// Alias of user to edit
+        user_alias: String,
+    },
     /// Displays current Git user
     Current,
     /// Displays all users in stored JSON file
     List,
+    /// Automatically picks a profile based on the repository's origin remote
+    Auto,
+    /// Installs a native `includeIf`-based config so Git picks the identity by path
+    InstallIncludeif,
+    /// Removes the `includeIf`-based config installed by `install-includeif`
+    UninstallIncludeif,
+}
+
+/// Git config scope to write identity settings to
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigScope {
+    /// Repository-local config (`.git/config`)
+    Local,
+    /// User-wide config (`~/.gitconfig`)
+    Global,
+    /// Machine-wide config (`/etc/gitconfig`)
+    System,
+    /// Config specific to the current worktree
+    Worktree,
+}
+
+impl ConfigScope {
+    /// Returns the `git config` flag (e.g. `--global`) for this scope, or `None` for the
+    /// implicit local scope
+    pub fn as_git_flag(&self) -> Option<&'static str> {
+        match self {
+            ConfigScope::Local => None,
+            ConfigScope::Global => Some("--global"),
+            ConfigScope::System => Some("--system"),
+            ConfigScope::Worktree => Some("--worktree"),
+        }
+    }
 }
\ No newline at end of file
@@ -27,4 +27,7 @@ pub enum AppError {
     /// Error during UTF-8 conversion.
     #[error("UTF-8 error: {0}")]
     Utf8Error(#[from] std::string::FromUtf8Error),
+    /// Error when a network request fails.
+    #[error("network error: {0}")]
+    Network(#[from] reqwest::Error),
 }
\ No newline at end of file
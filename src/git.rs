@@ -1,5 +1,6 @@
 use std::process::{Command, Output};
 
+use crate::cli::ConfigScope;
 use crate::error::AppError;
 
 /// Executes Git config get command
@@ -26,8 +27,55 @@ pub fn get_git_user(key: &str) -> Result<String, AppError> {
 /// # Arguments
 /// * `key` - Git config key to set (user.name or user.email)
 /// * `value` - Value to set for key (username or email)
-pub fn set_git_config(key: &str, value: &str) -> Result<(), AppError> {
-    let git_command_output: Output = Command::new("git").args(["config", key, value]).output()?;
+/// * `scope` - Config scope to write the value to
+pub fn set_git_config(key: &str, value: &str, scope: ConfigScope) -> Result<(), AppError> {
+    let mut args: Vec<&str> = vec!["config"];
+    if let Some(flag) = scope.as_git_flag() {
+        args.push(flag);
+    }
+    args.push(key);
+    args.push(value);
+
+    let git_command_output: Output = Command::new("git").args(args).output()?;
+
+    if !git_command_output.status.success() {
+        return Err(AppError::GitCommand(
+            String::from_utf8(git_command_output.stderr)?.trim().to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Executes a Git config unset command
+///
+/// # Arguments
+/// * `key` - Git config key to unset (e.g. user.signingkey)
+/// * `scope` - Config scope to clear the value from
+pub fn unset_git_config(key: &str, scope: ConfigScope) -> Result<(), AppError> {
+    let mut args: Vec<&str> = vec!["config", "--unset"];
+    if let Some(flag) = scope.as_git_flag() {
+        args.push(flag);
+    }
+    args.push(key);
+
+    let git_command_output: Output = Command::new("git").args(args).output()?;
+
+    // Ignore "key not found" failures: there is nothing to clear
+    if !git_command_output.status.success() && git_command_output.status.code() != Some(5) {
+        return Err(AppError::GitCommand(
+            String::from_utf8(git_command_output.stderr)?.trim().to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Gets the URL of the repository's `origin` remote
+pub fn get_remote_url() -> Result<String, AppError> {
+    let git_command_output: Output = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .output()?;
 
     if !git_command_output.status.success() {
         return Err(AppError::GitCommand(
@@ -35,7 +83,8 @@ pub fn set_git_config(key: &str, value: &str) -> Result<(), AppError> {
         ));
     }
 
-    Ok(())  
+    let value = String::from_utf8_lossy(&git_command_output.stdout).to_string();
+    Ok(value.trim().to_string())
 }
 
 /// Checks if current directory is in a Git repository for executing Git commands
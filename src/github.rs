@@ -0,0 +1,73 @@
+use serde::Deserialize;
+
+use crate::error::AppError;
+
+/// Subset of the GitHub users API response we care about
+#[derive(Deserialize, Debug)]
+struct GitHubUser {
+    login: String,
+    name: Option<String>,
+    email: Option<String>,
+}
+
+/// Subset of the GitHub public emails API response we care about
+#[derive(Deserialize, Debug)]
+struct GitHubPublicEmail {
+    email: String,
+    primary: bool,
+}
+
+/// A Git identity resolved from a GitHub account
+pub struct GitHubProfile {
+    pub git_username: String,
+    pub git_email: String,
+}
+
+/// Fetches a Git identity (name and email) for the given GitHub login
+///
+/// # Arguments
+/// * `login` - GitHub account login to look up
+///
+/// If the profile exposes no email and a `GITHUB_TOKEN` environment variable is
+/// set, the primary entry from the authenticated `/user/public_emails` endpoint
+/// (the public emails of the token's own account) is used. Failing that, the
+/// email falls back to `<login>@users.noreply.github.com`.
+pub fn fetch_github_profile(login: &str) -> Result<GitHubProfile, AppError> {
+    let client = reqwest::blocking::Client::builder()
+        .user_agent("gitus")
+        .build()?;
+
+    let user: GitHubUser = client
+        .get(format!("https://api.github.com/users/{}", login))
+        .send()?
+        .error_for_status()?
+        .json()?;
+
+    let git_username: String = user.name.unwrap_or_else(|| user.login.clone());
+
+    let git_email: String = match user.email {
+        Some(email) => email,
+        None => fetch_public_email(&client)?
+            .unwrap_or_else(|| format!("{}@users.noreply.github.com", user.login)),
+    };
+
+    Ok(GitHubProfile { git_username, git_email })
+}
+
+/// Looks up a primary email via the authenticated public emails endpoint, if a
+/// `GITHUB_TOKEN` is available
+fn fetch_public_email(client: &reqwest::blocking::Client) -> Result<Option<String>, AppError> {
+    let token = match std::env::var("GITHUB_TOKEN") {
+        Ok(token) => token,
+        Err(_) => return Ok(None),
+    };
+
+    let emails: Vec<GitHubPublicEmail> = client
+        .get("https://api.github.com/user/public_emails")
+        .bearer_auth(token)
+        .send()?
+        .error_for_status()?
+        .json()?;
+
+    Ok(emails.into_iter().find(|e| e.primary).map(|e| e.email))
+}
@@ -0,0 +1,121 @@
+use std::{fs, path::PathBuf};
+
+use crate::{error::AppError, profile::GitUserProfile, storage::get_home_dir};
+
+/// Directory (relative to the home dir) where per-profile identity files are written
+const GITUS_CONFIG_DIR: &str = ".config/gitus";
+/// Name of the user-wide Git config file the managed block is installed into
+const GLOBAL_GITCONFIG_FILE: &str = ".gitconfig";
+
+/// Marks the start of the block of config managed by `gitus`
+const BEGIN_MARKER: &str = "# >>> gitus managed includeIf block: do not edit by hand >>>";
+/// Marks the end of the block of config managed by `gitus`
+const END_MARKER: &str = "# <<< gitus managed includeIf block <<<";
+
+/// Writes one identity file per profile with a `workdir` set, and installs a matching
+/// `includeIf` stanza for each into the user's global `.gitconfig`
+pub fn install_includeif(users: &[GitUserProfile]) -> Result<(), AppError> {
+    let home_dir: PathBuf = get_home_dir()?;
+    let config_dir: PathBuf = home_dir.join(GITUS_CONFIG_DIR);
+    fs::create_dir_all(&config_dir)?;
+
+    let mut block = String::new();
+    block.push_str(BEGIN_MARKER);
+    block.push('\n');
+
+    for user in users {
+        let Some(workdir) = &user.workdir else {
+            continue;
+        };
+
+        let identity_path: PathBuf = config_dir.join(format!("{}.gitconfig", user.user_alias));
+        fs::write(&identity_path, render_identity_file(user))?;
+
+        block.push_str(&format!(
+            "[includeIf \"gitdir:{}/\"]\n\tpath = {}\n",
+            workdir.trim_end_matches('/'),
+            identity_path.to_string_lossy(),
+        ));
+    }
+
+    block.push_str(END_MARKER);
+    block.push('\n');
+
+    write_managed_block(&home_dir.join(GLOBAL_GITCONFIG_FILE), Some(&block))
+}
+
+/// Strips the managed `includeIf` block from the user's global `.gitconfig`, leaving
+/// any hand-edited config untouched
+pub fn uninstall_includeif() -> Result<(), AppError> {
+    let home_dir: PathBuf = get_home_dir()?;
+    let gitconfig_path: PathBuf = home_dir.join(GLOBAL_GITCONFIG_FILE);
+
+    // Nothing was ever installed, so there is nothing to uninstall
+    if !gitconfig_path.exists() {
+        return Ok(());
+    }
+
+    write_managed_block(&gitconfig_path, None)
+}
+
+/// Renders the identity file contents for a profile
+///
+/// Mirrors the settings `apply_signing_identity` applies on switch: the signing key
+/// plus `gpg.format` and `commit.gpgsign`, so a path-based identity behaves the same
+/// as one applied imperatively.
+fn render_identity_file(user: &GitUserProfile) -> String {
+    let mut identity = format!("[user]\n\tname = {}\n\temail = {}\n", user.git_username, user.git_email);
+    if let Some(key) = &user.signing_key {
+        identity.push_str(&format!("\tsigningkey = {}\n", key));
+        identity.push_str(&format!(
+            "[gpg]\n\tformat = {}\n",
+            user.signing_format.as_deref().unwrap_or("openpgp"),
+        ));
+        identity.push_str("[commit]\n\tgpgsign = true\n");
+    }
+    identity
+}
+
+/// Replaces the sentinel-delimited managed block in `gitconfig_path` with `block`,
+/// or removes it entirely when `block` is `None`, preserving the rest of the file
+fn write_managed_block(gitconfig_path: &PathBuf, block: Option<&str>) -> Result<(), AppError> {
+    let existing: String = if gitconfig_path.exists() {
+        fs::read_to_string(gitconfig_path)?
+    } else {
+        String::new()
+    };
+
+    let remainder: String = strip_managed_block(&existing);
+
+    let updated: String = match block {
+        Some(block) if remainder.is_empty() => block.to_string(),
+        Some(block) => format!("{}\n{}", remainder.trim_end(), block),
+        None => remainder,
+    };
+
+    fs::write(gitconfig_path, updated)?;
+    Ok(())
+}
+
+/// Removes the sentinel-delimited managed block from Git config file contents
+fn strip_managed_block(contents: &str) -> String {
+    let mut result = String::new();
+    let mut inside_block = false;
+
+    for line in contents.lines() {
+        if line.trim() == BEGIN_MARKER {
+            inside_block = true;
+            continue;
+        }
+        if line.trim() == END_MARKER {
+            inside_block = false;
+            continue;
+        }
+        if !inside_block {
+            result.push_str(line);
+            result.push('\n');
+        }
+    }
+
+    result
+}
@@ -4,20 +4,27 @@
 mod cli;
 mod error;
 mod git;
+mod github;
+mod includeif;
 mod menu;
 mod profile;
 mod storage;
 mod validation;
 
 use clap::Parser;
-use cli::{Cli, Commands};
+use cli::{Cli, Commands, ConfigScope};
 use colored::Colorize;
 use error::AppError;
-use git::{get_git_user, is_inside_git_repo, set_git_config};
+use git::{get_git_user, get_remote_url, is_inside_git_repo, set_git_config, unset_git_config};
+use github::fetch_github_profile;
+use includeif::{install_includeif, uninstall_includeif};
 use menu::run_menu;
 use profile::GitUserProfile;
 use storage::{check_if_users_exist, load_users, save_users};
-use validation::{validate_input_alias, validate_input_email, validate_input_username};
+use validation::{
+    prompt_optional, prompt_until_valid, validate_input_alias, validate_input_email, validate_input_signing_format,
+    validate_input_username,
+};
 
 
 /// Option text to return back to main menu
@@ -25,11 +32,22 @@ const BACK_OPTION: &str = "back";
 
 /// Entry point for application
 fn main() -> Result<(), AppError>  {
-    if !is_inside_git_repo()? {
+    let cli = Cli::parse();
+
+    // Setting a global (or system) identity, or managing the global includeIf config,
+    // does not require a repository to stand in
+    let requires_git_repo = !matches!(
+        &cli.command,
+        Some(Commands::Switch { scope: ConfigScope::Global | ConfigScope::System, .. })
+            | Some(Commands::InstallIncludeif)
+            | Some(Commands::UninstallIncludeif)
+    );
+
+    if requires_git_repo && !is_inside_git_repo()? {
         return Err(AppError::NotInGitRepository);
     }
 
-    if let Err(e) = run_app() {
+    if let Err(e) = run_app(cli) {
         eprintln!("{}: {}", "error running app".red(), e);
     }
 
@@ -37,25 +55,109 @@ fn main() -> Result<(), AppError>  {
 }
 
 /// Main application logic for command execution
-fn run_app() -> Result<(), AppError> {
-    let cli = Cli::parse();
-
+fn run_app(cli: Cli) -> Result<(), AppError> {
     match cli.command {
-        Some(Commands::Switch { user_alias }) => switch_user(&user_alias),
+        Some(Commands::Switch { user_alias, scope }) => switch_user(&user_alias, scope),
         Some(Commands::Add {
             git_username,
             git_email,
             user_alias,
-        }) => add_user(&git_username, &git_email, &user_alias),
+            from_github,
+        }) => match from_github {
+            Some(login) => {
+                if git_username.is_some() || git_email.is_some() || user_alias.is_some() {
+                    return Err(AppError::Validation(
+                        "git username/email/alias cannot be combined with --from-github".to_string(),
+                    ));
+                }
+                add_user_from_github(&login)
+            }
+            None => add_user(
+                &git_username.ok_or_else(|| AppError::Validation("git username is required".to_string()))?,
+                &git_email.ok_or_else(|| AppError::Validation("git email is required".to_string()))?,
+                &user_alias.ok_or_else(|| AppError::Validation("user alias is required".to_string()))?,
+            ),
+        },
         Some(Commands::Delete { user_alias }) => delete_user(&user_alias),
+        Some(Commands::Edit { user_alias }) => edit_user(&user_alias),
         Some(Commands::Current) => show_current_user(),
         Some(Commands::List) => list_all_users(),
+        Some(Commands::Auto) => auto_switch_user(),
+        Some(Commands::InstallIncludeif) => install_includeif_config(),
+        Some(Commands::UninstallIncludeif) => uninstall_includeif_config(),
         None => run_menu(),
     }
 }
 
+/// Writes the native `includeIf` config for every profile with a `workdir` set
+fn install_includeif_config() -> Result<(), AppError> {
+    let users: Vec<GitUserProfile> = load_users()?;
+    check_if_users_exist(&users)?;
+
+    install_includeif(&users)?;
+    print_success("installed includeIf config");
+    Ok(())
+}
+
+/// Removes the native `includeIf` config installed by `install-includeif`
+fn uninstall_includeif_config() -> Result<(), AppError> {
+    uninstall_includeif()?;
+    print_success("uninstalled includeIf config");
+    Ok(())
+}
+
+/// Picks a profile whose `match_remotes` patterns match the repository's origin remote
+/// and switches to it
+fn auto_switch_user() -> Result<(), AppError> {
+    let users: Vec<GitUserProfile> = load_users()?;
+    check_if_users_exist(&users)?;
+
+    let remote_url: String = get_remote_url()?;
+
+    let matches: Vec<&GitUserProfile> = users
+        .iter()
+        .filter(|user| user.match_remotes.iter().any(|pattern| remote_matches_pattern(&remote_url, pattern)))
+        .collect();
+
+    match matches.as_slice() {
+        [] => Err(AppError::Validation(format!(
+            "no profile matches remote '{}'",
+            remote_url
+        ))),
+        [user] => switch_user(&user.user_alias, ConfigScope::Local),
+        _ => Err(AppError::Validation(format!(
+            "multiple profiles match remote '{}': {}",
+            remote_url,
+            matches.iter().map(|user| user.user_alias.as_str()).collect::<Vec<_>>().join(", ")
+        ))),
+    }
+}
+
+/// Checks whether a remote URL matches a `match_remotes` pattern
+///
+/// Patterns containing glob characters (`*` or `?`) are matched as globs;
+/// all other patterns are matched as plain substrings.
+fn remote_matches_pattern(remote_url: &str, pattern: &str) -> bool {
+    if pattern.contains('*') || pattern.contains('?') {
+        // `glob::Pattern` matches the whole string, but these patterns are meant to match
+        // a substring of the remote (e.g. a host or path segment) — anchor with `*` on
+        // whichever ends aren't already anchored so unrelated prefix/suffix still matches
+        let anchored_pattern = format!(
+            "{}{}{}",
+            if pattern.starts_with('*') { "" } else { "*" },
+            pattern,
+            if pattern.ends_with('*') { "" } else { "*" },
+        );
+        glob::Pattern::new(&anchored_pattern)
+            .map(|glob_pattern| glob_pattern.matches(remote_url))
+            .unwrap_or(false)
+    } else {
+        remote_url.contains(pattern)
+    }
+}
+
 // Switches current Git user to selected user profile
-pub fn switch_user(user_alias: &str) -> Result<(), AppError> {
+pub fn switch_user(user_alias: &str, scope: ConfigScope) -> Result<(), AppError> {
     let users: Vec<GitUserProfile> = load_users()?;
     check_if_users_exist(&users)?;
 
@@ -64,8 +166,9 @@ pub fn switch_user(user_alias: &str) -> Result<(), AppError> {
     }
 
     if let Some(user) = users.iter().find(|user| user.user_alias == user_alias) {
-        set_git_config("user.name", &user.git_username)?;
-        set_git_config("user.email", &user.git_email)?;
+        set_git_config("user.name", &user.git_username, scope)?;
+        set_git_config("user.email", &user.git_email, scope)?;
+        apply_signing_identity(user, scope)?;
         println!("{} {}", "switched to user:".green(), user.user_alias);
         Ok(())
     } else {
@@ -73,19 +176,40 @@ pub fn switch_user(user_alias: &str) -> Result<(), AppError> {
     }
 }
 
+/// Applies (or clears) the signing key and format for the given user profile
+fn apply_signing_identity(user: &GitUserProfile, scope: ConfigScope) -> Result<(), AppError> {
+    match &user.signing_key {
+        Some(key) => {
+            set_git_config("user.signingkey", key, scope)?;
+            set_git_config("gpg.format", user.signing_format.as_deref().unwrap_or("openpgp"), scope)?;
+            set_git_config("commit.gpgsign", "true", scope)?;
+        }
+        None => {
+            unset_git_config("user.signingkey", scope)?;
+            unset_git_config("gpg.format", scope)?;
+            unset_git_config("commit.gpgsign", scope)?;
+        }
+    }
+    Ok(())
+}
+
 /// Adds a new user profile to the stored profiles
 fn add_user(git_username: &str, git_email: &str, user_alias: &str) -> Result<(), AppError> {
     let mut users: Vec<GitUserProfile> = load_users()?;
 
     // Input validation
-    validate_input_username(git_username, &users)?;
-    validate_input_email(git_email, &users)?;
-    validate_input_alias(user_alias, &users)?;
+    validate_input_username(git_username, &users, None)?;
+    validate_input_email(git_email, &users, None)?;
+    validate_input_alias(user_alias, &users, None)?;
 
     users.push(GitUserProfile {
         git_username: git_username.to_string(),
         git_email: git_email.to_string(),
         user_alias: user_alias.to_string(),
+        signing_key: None,
+        signing_format: None,
+        match_remotes: Vec::new(),
+        workdir: None,
     });
 
     save_users(&users)?;
@@ -93,6 +217,103 @@ fn add_user(git_username: &str, git_email: &str, user_alias: &str) -> Result<(),
     Ok(())
 }
 
+/// Adds a new user profile populated from a GitHub account's public profile
+fn add_user_from_github(login: &str) -> Result<(), AppError> {
+    let mut users: Vec<GitUserProfile> = load_users()?;
+    let profile = fetch_github_profile(login)?;
+
+    validate_input_username(&profile.git_username, &users, None)?;
+    validate_input_email(&profile.git_email, &users, None)?;
+
+    let alias: String = prompt_until_valid(
+        &format!("{}", "enter alias:".blue()),
+        None,
+        |input| validate_input_alias(input, &users, None),
+    )?;
+
+    users.push(GitUserProfile {
+        git_username: profile.git_username,
+        git_email: profile.git_email,
+        user_alias: alias,
+        signing_key: None,
+        signing_format: None,
+        match_remotes: Vec::new(),
+        workdir: None,
+    });
+
+    save_users(&users)?;
+    print_success("added user");
+    Ok(())
+}
+
+/// Edits an existing user profile in place, prompting for each field pre-filled
+/// with its current value
+fn edit_user(user_alias: &str) -> Result<(), AppError> {
+    let mut users: Vec<GitUserProfile> = load_users()?;
+    check_if_users_exist(&users)?;
+
+    let index = users
+        .iter()
+        .position(|user| user.user_alias == user_alias)
+        .ok_or_else(|| AppError::UserNotFound(user_alias.to_string()))?;
+
+    let username: String = prompt_until_valid(
+        &format!("{}", "enter git username:".blue()),
+        Some(&users[index].git_username),
+        |input| validate_input_username(input, &users, Some(user_alias)),
+    )?;
+
+    let email: String = prompt_until_valid(
+        &format!("{}", "enter git email:".blue()),
+        Some(&users[index].git_email),
+        |input| validate_input_email(input, &users, Some(user_alias)),
+    )?;
+
+    let alias: String = prompt_until_valid(
+        &format!("{}", "enter alias:".blue()),
+        Some(&users[index].user_alias),
+        |input| validate_input_alias(input, &users, Some(user_alias)),
+    )?;
+
+    let signing_key: Option<String> = prompt_optional(
+        &format!("{}", "enter signing key, blank to clear:".blue()),
+        users[index].signing_key.as_deref(),
+    )?;
+
+    let signing_format: Option<String> = match &signing_key {
+        Some(_) => Some(prompt_until_valid(
+            &format!("{}", "enter signing format (openpgp/ssh):".blue()),
+            Some(users[index].signing_format.as_deref().unwrap_or("openpgp")),
+            validate_input_signing_format,
+        )?),
+        None => None,
+    };
+
+    let match_remotes: Vec<String> = prompt_optional(
+        &format!("{}", "enter remote match patterns, comma-separated, blank to clear:".blue()),
+        Some(&users[index].match_remotes.join(",")),
+    )?
+    .map(|patterns| patterns.split(',').map(|pattern| pattern.trim().to_string()).filter(|pattern| !pattern.is_empty()).collect())
+    .unwrap_or_default();
+
+    let workdir: Option<String> = prompt_optional(
+        &format!("{}", "enter workdir for install-includeif, blank to clear:".blue()),
+        users[index].workdir.as_deref(),
+    )?;
+
+    users[index].git_username = username;
+    users[index].git_email = email;
+    users[index].user_alias = alias;
+    users[index].signing_key = signing_key;
+    users[index].signing_format = signing_format;
+    users[index].match_remotes = match_remotes;
+    users[index].workdir = workdir;
+
+    save_users(&users)?;
+    print_success("edited user");
+    Ok(())
+}
+
 /// Deletes selected user profile from storage
 fn delete_user(user_alias: &str) -> Result<(), AppError> {
     let mut users: Vec<GitUserProfile> = load_users()?;
@@ -1,7 +1,7 @@
 use colored::Colorize;
-use inquire::Select;
+use inquire::{Select, Text};
 
-use crate::{add_user, check_if_users_exist, delete_user, error::AppError, list_all_users, show_current_user, storage::load_users, switch_user, validation::{prompt_until_valid, validate_input_alias, validate_input_email, validate_input_username}, GitUserProfile, BACK_OPTION};
+use crate::{add_user, check_if_users_exist, cli::ConfigScope, delete_user, error::AppError, list_all_users, show_current_user, storage::load_users, switch_user, validation::{prompt_until_valid, validate_input_alias, validate_input_email, validate_input_username}, GitUserProfile, BACK_OPTION};
 
 /// Runs interactive menu interface
 pub fn run_menu() -> Result<(), AppError> {
@@ -38,12 +38,12 @@ fn menu_switch_user() -> Result<(), AppError>  {
     let users: Vec<GitUserProfile> = load_users()?;
     check_if_users_exist(&users)?;
 
-    let user_aliases: Vec<String> = build_alias_list(&users);
+    let user_aliases: Vec<String> = fuzzy_ranked_alias_list(&users)?;
     let alias_to_switch: String = Select::new(&format!("{}", "select user to switch:".blue()), user_aliases)
         .prompt()?;
 
     if alias_to_switch != BACK_OPTION {
-        switch_user(&alias_to_switch)?;
+        switch_user(&alias_to_switch, ConfigScope::Local)?;
     }
     
     Ok(())
@@ -56,17 +56,20 @@ fn menu_add_user() -> Result<(), AppError> {
     // Input validation
     let username: String = prompt_until_valid(
         &format!("{}", "enter git username:".blue()),
-        |input| validate_input_username(input, &users),
+        None,
+        |input| validate_input_username(input, &users, None),
     )?;
 
     let email: String = prompt_until_valid(
-        &format!("{}", "enter git email:".blue()), 
-        |input| validate_input_email(input, &users)
+        &format!("{}", "enter git email:".blue()),
+        None,
+        |input| validate_input_email(input, &users, None)
     )?;
 
     let alias: String = prompt_until_valid(
-        &format!("{}", "enter alias:".blue()), 
-        |input| validate_input_alias(input, &users)
+        &format!("{}", "enter alias:".blue()),
+        None,
+        |input| validate_input_alias(input, &users, None)
     )?;
     
     add_user(&username, &email, &alias)?;
@@ -79,7 +82,7 @@ fn menu_delete_user() -> Result<(), AppError> {
     let users: Vec<GitUserProfile> = load_users()?;
     check_if_users_exist(&users)?;
 
-    let user_aliases: Vec<String> = build_alias_list(&users);
+    let user_aliases: Vec<String> = fuzzy_ranked_alias_list(&users)?;
     let alias_to_delete: String = Select::new(&format!("{}", "select user to delete:".blue()), user_aliases)
         .prompt()?;
 
@@ -97,4 +100,68 @@ pub fn build_alias_list(users: &[GitUserProfile]) -> Vec<String> {
         .collect();
     user_aliases.push("back".to_string());
     user_aliases
+}
+
+/// Prompts for a fuzzy search query and returns the alias list ranked by relevance
+///
+/// An empty query leaves the list in its original order.
+fn fuzzy_ranked_alias_list(users: &[GitUserProfile]) -> Result<Vec<String>, AppError> {
+    let query: String = Text::new(&format!("{}", "search alias (leave blank to show all):".blue()))
+        .with_default("")
+        .prompt()?;
+
+    if query.is_empty() {
+        return Ok(build_alias_list(users));
+    }
+
+    let mut scored: Vec<(i32, String)> = users
+        .iter()
+        .filter_map(|user| fuzzy_score(&query, &user.user_alias).map(|score| (score, user.user_alias.clone())))
+        .collect();
+    scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+
+    let mut ranked_aliases: Vec<String> = scored.into_iter().map(|(_, alias)| alias).collect();
+    ranked_aliases.push(BACK_OPTION.to_string());
+
+    Ok(ranked_aliases)
+}
+
+/// Scores how well `query` fuzzy-matches `candidate`
+///
+/// Walks the query characters left-to-right, matching each in order against the
+/// lowercased candidate. Returns `None` if any query character can't be found.
+/// Otherwise accumulates a base point per matched character, a bonus for
+/// consecutive matches, a bonus for matches landing on a word boundary (start of
+/// string or right after `-`/`_`/space), and a small penalty per skipped character.
+fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    const CONSECUTIVE_BONUS: i32 = 5;
+    const WORD_BOUNDARY_BONUS: i32 = 8;
+
+    let candidate_lower = candidate.to_lowercase();
+    let candidate_chars: Vec<char> = candidate_lower.chars().collect();
+
+    let mut score = 0;
+    let mut search_from = 0;
+    let mut last_match_idx: Option<usize> = None;
+
+    for query_char in query.to_lowercase().chars() {
+        let match_idx = candidate_chars[search_from..].iter().position(|&c| c == query_char)? + search_from;
+
+        score += 1;
+
+        match last_match_idx {
+            Some(last) if match_idx == last + 1 => score += CONSECUTIVE_BONUS,
+            Some(last) => score -= (match_idx - last - 1) as i32,
+            None => score -= match_idx as i32,
+        }
+
+        if match_idx == 0 || matches!(candidate_chars[match_idx - 1], '-' | '_' | ' ') {
+            score += WORD_BOUNDARY_BONUS;
+        }
+
+        last_match_idx = Some(match_idx);
+        search_from = match_idx + 1;
+    }
+
+    Some(score)
 }
\ No newline at end of file
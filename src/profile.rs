@@ -9,4 +9,18 @@ pub struct GitUserProfile {
     pub git_email: String,
     /// Unique user alias
     pub user_alias: String,
+    /// Optional signing key (GPG key ID or path to an SSH key) to sign commits with
+    #[serde(default)]
+    pub signing_key: Option<String>,
+    /// Optional signing format ("openpgp" or "ssh") for `signing_key`
+    #[serde(default)]
+    pub signing_format: Option<String>,
+    /// Optional glob/substring patterns matched against a repository's remote URL
+    /// (e.g. `github.com:acme/*`, `*.corp.internal`) to auto-select this profile
+    #[serde(default)]
+    pub match_remotes: Vec<String>,
+    /// Optional directory root (e.g. `~/work`) this profile applies to when installed
+    /// via `gitus install-includeif`
+    #[serde(default)]
+    pub workdir: Option<String>,
 }
\ No newline at end of file
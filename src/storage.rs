@@ -5,11 +5,14 @@ use crate::{error::AppError, GitUserProfile};
 /// User profiles file in user's home directory
 const GLOBAL_GIT_PROFILES_FILE: &str = "user_profiles.json";
 
+/// Gets the current user's home directory
+pub fn get_home_dir() -> Result<PathBuf, AppError> {
+    dirs::home_dir().ok_or_else(|| AppError::Validation("failed to find the home directory".to_string()))
+}
+
 /// Gets the path to the profiles file
 pub fn get_global_profile_path() -> Result<String, AppError> {
-    let home_dir: PathBuf = dirs::home_dir().ok_or_else(|| {
-        AppError::Validation("failed to find the home directory".to_string())
-    })?;
+    let home_dir: PathBuf = get_home_dir()?;
     let profile_file_path: PathBuf = home_dir.join(GLOBAL_GIT_PROFILES_FILE);
     Ok(profile_file_path.to_string_lossy().into_owned())
 }
@@ -12,29 +12,56 @@ const MAX_EMAIL_LENGTH: usize = 100;
 const MAX_ALIAS_LENGTH: usize = 30;
 
 /// Prompts user for input until valid input is provided
-pub fn prompt_until_valid<F>(prompt_message: &str, input_validation: F) -> Result<String, AppError>
+///
+/// # Arguments
+/// * `initial_value` - Value to pre-fill the prompt with (e.g. when editing a profile)
+pub fn prompt_until_valid<F>(prompt_message: &str, initial_value: Option<&str>, input_validation: F) -> Result<String, AppError>
 where
     F: Fn(&str) -> Result<(), AppError>,
 {
     loop {
-        let input: String = Text::new(prompt_message).prompt()?;
+        let mut text = Text::new(prompt_message);
+        if let Some(value) = initial_value {
+            text = text.with_initial_value(value);
+        }
+
+        let input: String = text.prompt()?;
         match input_validation(&input) {
             Ok(_) => break Ok(input),
             Err(AppError::Validation(msg)) => println!("{}", msg.red()),
-            Err(e) => return Err(e), 
+            Err(e) => return Err(e),
         }
     }
 }
 
+/// Prompts for an optional value, pre-filled with `initial_value`; a blank answer clears it
+pub fn prompt_optional(prompt_message: &str, initial_value: Option<&str>) -> Result<Option<String>, AppError> {
+    let mut text = Text::new(prompt_message);
+    if let Some(value) = initial_value {
+        text = text.with_initial_value(value);
+    }
+
+    let input: String = text.prompt()?;
+    let input: &str = input.trim();
+    Ok(if input.is_empty() { None } else { Some(input.to_string()) })
+}
+
 // Validate input helper functions
 
 /// Validates username input
-pub fn validate_input_username(name: &str, existing_users: &[GitUserProfile]) -> Result<(), AppError> {
+///
+/// # Arguments
+/// * `skip_alias` - Alias of a profile to exclude from uniqueness checks (e.g. the
+///   profile currently being edited)
+pub fn validate_input_username(name: &str, existing_users: &[GitUserProfile], skip_alias: Option<&str>) -> Result<(), AppError> {
     if name.is_empty() {
         Err(AppError::Validation("Username cannot be empty".to_string()))
     } else if name.len() > MAX_USERNAME_LENGTH {
         Err(AppError::Validation(format!("username too long, max {} characters)", MAX_USERNAME_LENGTH)))
-    } else if existing_users.iter().any(|user| user.git_username == name) {
+    } else if existing_users
+        .iter()
+        .any(|user| Some(user.user_alias.as_str()) != skip_alias && user.git_username == name)
+    {
         Err(AppError::Validation("Username already exists".to_string()))
     } else {
         Ok(())
@@ -42,14 +69,21 @@ pub fn validate_input_username(name: &str, existing_users: &[GitUserProfile]) ->
 }
 
 /// Validates email input
-pub fn validate_input_email(email: &str, existing_users: &[GitUserProfile]) -> Result<(), AppError> {
+///
+/// # Arguments
+/// * `skip_alias` - Alias of a profile to exclude from uniqueness checks (e.g. the
+///   profile currently being edited)
+pub fn validate_input_email(email: &str, existing_users: &[GitUserProfile], skip_alias: Option<&str>) -> Result<(), AppError> {
     if email.is_empty() {
         Err(AppError::Validation("Email cannot be empty".to_string()))
     } else if email.len() > MAX_EMAIL_LENGTH {
         Err(AppError::Validation(format!("email too long, max {} characters",MAX_EMAIL_LENGTH)))
     } else if !email.validate_email() {
         Err(AppError::Validation("Invalid email format".to_string()))
-    } else if existing_users.iter().any(|user| user.git_email == email) {
+    } else if existing_users
+        .iter()
+        .any(|user| Some(user.user_alias.as_str()) != skip_alias && user.git_email == email)
+    {
         Err(AppError::Validation("Email already exists".to_string()))
     } else {
         Ok(())
@@ -57,16 +91,32 @@ pub fn validate_input_email(email: &str, existing_users: &[GitUserProfile]) -> R
 }
 
 /// Validates an alias input
-pub fn validate_input_alias(alias: &str, existing_users: &[GitUserProfile]) -> Result<(), AppError> {
+///
+/// # Arguments
+/// * `skip_alias` - Alias of a profile to exclude from uniqueness checks (e.g. the
+///   profile currently being edited)
+pub fn validate_input_alias(alias: &str, existing_users: &[GitUserProfile], skip_alias: Option<&str>) -> Result<(), AppError> {
     if alias.is_empty() {
         Err(AppError::Validation("Alias cannot be empty".to_string()))
     } else if alias.len() > MAX_ALIAS_LENGTH {
         Err(AppError::Validation(format!("Alias too long (max {} characters)",MAX_ALIAS_LENGTH)))
     } else if alias == BACK_OPTION {
         Err(AppError::Validation("Alias cannot be 'back'".to_string()))
-    } else if existing_users.iter().any(|user| user.user_alias == alias) {
+    } else if existing_users
+        .iter()
+        .any(|user| Some(user.user_alias.as_str()) != skip_alias && user.user_alias == alias)
+    {
         Err(AppError::Validation("Alias already exists".to_string()))
     } else {
         Ok(())
     }
+}
+
+/// Validates a signing format input
+pub fn validate_input_signing_format(format: &str) -> Result<(), AppError> {
+    if format == "openpgp" || format == "ssh" {
+        Ok(())
+    } else {
+        Err(AppError::Validation("signing format must be 'openpgp' or 'ssh'".to_string()))
+    }
 }
\ No newline at end of file